@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use stwo_prover::core::backend::simd::fold::fold_simd;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::fields::{ExtensionOf, Field};
+use stwo_prover::core::poly::utils::fold;
+
+/// The original recursive `fold`, used as a baseline.
+fn fold_recursive<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+    let n = values.len();
+    assert_eq!(n, 1 << folding_factors.len());
+    if n == 1 {
+        return values[0].into();
+    }
+    let (lhs_values, rhs_values) = values.split_at(n / 2);
+    let (folding_factor, folding_factors) = folding_factors.split_first().unwrap();
+    let lhs_val = fold_recursive(lhs_values, folding_factors);
+    let rhs_val = fold_recursive(rhs_values, folding_factors);
+    lhs_val + rhs_val * *folding_factor
+}
+
+fn fold_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fold");
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for log_size in 10..=22 {
+        let values: Vec<BaseField> = (0..1 << log_size).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..log_size).map(|_| rng.gen()).collect();
+        group.throughput(Throughput::Elements(1 << log_size));
+        group.bench_function(BenchmarkId::new("recursive", log_size), |b| {
+            b.iter(|| fold_recursive(black_box(&values), black_box(&folding_factors)))
+        });
+        group.bench_function(BenchmarkId::new("cpu", log_size), |b| {
+            b.iter(|| fold(black_box(&values), black_box(&folding_factors)))
+        });
+        group.bench_function(BenchmarkId::new("simd", log_size), |b| {
+            b.iter(|| fold_simd(black_box(&values), black_box(&folding_factors)))
+        });
+        #[cfg(feature = "parallel")]
+        group.bench_function(BenchmarkId::new("parallel", log_size), |b| {
+            b.iter(|| {
+                stwo_prover::core::poly::utils::fold_parallel(
+                    black_box(&values),
+                    black_box(&folding_factors),
+                )
+            })
+        });
+    }
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = fold_benches);
+criterion_main!(benches);
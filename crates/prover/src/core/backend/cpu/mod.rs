@@ -58,6 +58,9 @@ impl<T: Debug + Clone + Default> Column<T> for Vec<T> {
     fn to_cpu(&self) -> Vec<T> {
         self.clone()
     }
+    fn to_cpu_into(&self, dst: &mut Vec<T>) {
+        dst.clone_from(self);
+    }
     fn len(&self) -> usize {
         self.len()
     }
@@ -94,4 +97,24 @@ mod tests {
 
         assert_eq!(expected, dst);
     }
+
+    #[test]
+    fn to_cpu_into_grows_dst() {
+        let column = (0..10).map(QM31::from).collect_vec();
+        let mut dst = vec![QM31::from(7); 3];
+
+        column.to_cpu_into(&mut dst);
+
+        assert_eq!(dst, column);
+    }
+
+    #[test]
+    fn to_cpu_into_truncates_dst() {
+        let column = (0..3).map(QM31::from).collect_vec();
+        let mut dst = vec![QM31::from(7); 10];
+
+        column.to_cpu_into(&mut dst);
+
+        assert_eq!(dst, column);
+    }
 }
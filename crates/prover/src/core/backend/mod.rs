@@ -53,6 +53,12 @@ pub trait Column<T>: Clone + Debug + FromIterator<T> {
     unsafe fn uninitialized(len: usize) -> Self;
     /// Returns a cpu vector of the column.
     fn to_cpu(&self) -> Vec<T>;
+    /// Copies the column into `dst`, replacing its contents and reusing its allocation where
+    /// possible.
+    fn to_cpu_into(&self, dst: &mut Vec<T>) {
+        dst.clear();
+        dst.extend(self.to_cpu());
+    }
     /// Returns the length of the column.
     fn len(&self) -> usize;
     /// Returns true if the column is empty.
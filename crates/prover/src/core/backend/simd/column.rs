@@ -97,6 +97,11 @@ impl Column<BaseField> for BaseColumn {
         self.as_slice().to_vec()
     }
 
+    fn to_cpu_into(&self, dst: &mut Vec<BaseField>) {
+        dst.clear();
+        dst.extend_from_slice(self.as_slice());
+    }
+
     fn len(&self) -> usize {
         self.length
     }
@@ -161,6 +166,16 @@ impl Column<CM31> for CM31Column {
             .collect()
     }
 
+    fn to_cpu_into(&self, dst: &mut Vec<CM31>) {
+        dst.clear();
+        dst.extend(
+            self.data
+                .iter()
+                .flat_map(|x| x.to_array())
+                .take(self.length),
+        );
+    }
+
     fn len(&self) -> usize {
         self.length
     }
@@ -276,6 +291,16 @@ impl Column<SecureField> for SecureColumn {
             .collect()
     }
 
+    fn to_cpu_into(&self, dst: &mut Vec<SecureField>) {
+        dst.clear();
+        dst.extend(
+            self.data
+                .iter()
+                .flat_map(|x| x.to_array())
+                .take(self.length),
+        );
+    }
+
     fn len(&self) -> usize {
         self.length
     }
@@ -674,6 +699,35 @@ mod tests {
         assert_eq!(res.to_cpu(), values);
     }
 
+    #[test]
+    fn base_column_to_cpu_into_works() {
+        let values: [BaseField; 30] = array::from_fn(BaseField::from);
+        let column = values.into_iter().collect::<BaseColumn>();
+        let mut short_dst = vec![BaseField::from(7); 3];
+        let mut long_dst = vec![BaseField::from(7); 100];
+
+        column.to_cpu_into(&mut short_dst);
+        column.to_cpu_into(&mut long_dst);
+
+        assert_eq!(short_dst, values);
+        assert_eq!(long_dst, values);
+    }
+
+    #[test]
+    fn secure_column_to_cpu_into_works() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: [SecureField; 30] = rng.gen();
+        let column = values.into_iter().collect::<SecureColumn>();
+        let mut short_dst = vec![SecureField::from(7); 3];
+        let mut long_dst = vec![SecureField::from(7); 100];
+
+        column.to_cpu_into(&mut short_dst);
+        column.to_cpu_into(&mut long_dst);
+
+        assert_eq!(short_dst, values);
+        assert_eq!(long_dst, values);
+    }
+
     #[test]
     fn test_base_column_chunks_mut() {
         let values: [BaseField; N_LANES * 7] = array::from_fn(BaseField::from);
@@ -0,0 +1,113 @@
+use super::m31::{PackedBaseField, LOG_N_LANES, N_LANES};
+use super::qm31::PackedSecureField;
+use crate::core::fields::m31::BaseField;
+use crate::core::fields::qm31::SecureField;
+use crate::core::poly::utils::fold;
+
+/// Same as [`fold`] but folds [`N_LANES`] values at a time using packed arithmetic.
+///
+/// The values are split into vectors of [`N_LANES`] and all layers above the innermost
+/// [`LOG_N_LANES`] are folded lane-wise across vectors, since every lane shares the same folding
+/// factor. The remaining layers, whose pairs lie within a single vector, are folded with the
+/// scalar [`fold`]. On targets without SIMD support the packed types lower to scalar code.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+pub fn fold_simd(values: &[BaseField], folding_factors: &[SecureField]) -> SecureField {
+    let n = values.len();
+    assert_eq!(n, 1 << folding_factors.len());
+    if n < N_LANES {
+        return fold(values, folding_factors);
+    }
+
+    let packed_values = values
+        .array_chunks()
+        .map(|&chunk| PackedBaseField::from_array(chunk));
+    fold_packed_iter(packed_values, folding_factors)
+}
+
+/// Same as [`fold`] over the unpacked values of `values`.
+///
+/// Every lane is folded, so `values.len() * N_LANES` must be the real number of values. The data
+/// of a [`BaseColumn`](super::column::BaseColumn) only qualifies if the column has no padding
+/// lanes, i.e. its length is a multiple of [`N_LANES`].
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`], counting the unpacked values.
+pub fn fold_packed(values: &[PackedBaseField], folding_factors: &[SecureField]) -> SecureField {
+    assert_eq!(values.len() * N_LANES, 1 << folding_factors.len());
+    fold_packed_iter(values.iter().copied(), folding_factors)
+}
+
+/// Folds the vectors lane-wise and then reduces the lanes with the innermost factors.
+fn fold_packed_iter(
+    values: impl Iterator<Item = PackedBaseField>,
+    folding_factors: &[SecureField],
+) -> SecureField {
+    let (outer_folding_factors, inner_folding_factors) =
+        folding_factors.split_at(folding_factors.len() - LOG_N_LANES as usize);
+    let lanes = fold_vectors(values, outer_folding_factors);
+    fold(&lanes.to_array(), inner_folding_factors)
+}
+
+/// Folds `2^folding_factors.len()` vectors lane-wise.
+fn fold_vectors(
+    mut values: impl Iterator<Item = PackedBaseField>,
+    folding_factors: &[SecureField],
+) -> PackedSecureField {
+    let Some((&innermost_factor, folding_factors)) = folding_factors.split_last() else {
+        return values.next().unwrap().into();
+    };
+
+    let innermost_factor = PackedSecureField::broadcast(innermost_factor);
+    let mut layer: Vec<PackedSecureField> = values
+        .array_chunks()
+        .map(|[lhs, rhs]| innermost_factor * rhs + lhs)
+        .collect();
+
+    for &folding_factor in folding_factors.iter().rev() {
+        let folding_factor = PackedSecureField::broadcast(folding_factor);
+        let n_folded = layer.len() / 2;
+        for i in 0..n_folded {
+            layer[i] = layer[2 * i] + layer[2 * i + 1] * folding_factor;
+        }
+        layer.truncate(n_folded);
+    }
+
+    layer[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_packed, fold_simd};
+    use crate::core::backend::simd::column::BaseColumn;
+    use crate::core::backend::simd::SimdBackend;
+    use crate::core::backend::{Column, CpuBackend};
+    use crate::core::poly::utils::fold;
+    use crate::core::test_utils::{gen_random_base_column, gen_random_folding_factors};
+
+    #[test]
+    fn fold_simd_matches_fold() {
+        for log_n in 0..12 {
+            let values = gen_random_base_column::<CpuBackend>(log_n, 0);
+            let folding_factors = gen_random_folding_factors(log_n as usize, 1);
+
+            let res = fold_simd(&values, &folding_factors);
+
+            assert_eq!(res, fold(&values, &folding_factors));
+        }
+    }
+
+    #[test]
+    fn fold_packed_matches_fold() {
+        const LOG_N: u32 = 14;
+        let values: BaseColumn = gen_random_base_column::<SimdBackend>(LOG_N, 0);
+        let folding_factors = gen_random_folding_factors(LOG_N as usize, 1);
+
+        let res = fold_packed(&values.data, &folding_factors);
+
+        assert_eq!(res, fold(&values.to_cpu(), &folding_factors));
+    }
+}
@@ -13,6 +13,7 @@ pub mod cm31;
 pub mod column;
 pub mod domain;
 pub mod fft;
+pub mod fold;
 pub mod fri;
 mod grind;
 pub mod lookups;
@@ -1,7 +1,10 @@
+use itertools::Itertools;
+use thiserror::Error;
+
 use super::line::LineDomain;
 use crate::core::fields::{ExtensionOf, Field};
 
-/// Folds values recursively in `O(n)` by a hierarchical application of folding factors.
+/// Folds values in `O(n)` by a hierarchical application of folding factors.
 ///
 /// i.e. folding `n = 8` values with `folding_factors = [x, y, z]`:
 ///
@@ -17,20 +20,514 @@ use crate::core::fields::{ExtensionOf, Field};
 ///
 /// # Panics
 ///
-/// Panics if the number of values is not a power of two or if an incorrect number of of folding
+/// Panics if the number of values is not a power of two or if an incorrect number of folding
 /// factors is provided.
-// TODO(Andrew): Can be made to run >10x faster by unrolling lower layers of recursion
 pub fn fold<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+    fold_with_order(values, folding_factors, FoldOrder::OutermostFirst)
+}
+
+/// The order in which [`fold_with_order`] consumes folding factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoldOrder {
+    /// `folding_factors[0]` is applied at the top layer, combining the two halves of the values.
+    /// This is the order [`fold`] uses, and the order of the coefficient evaluation mappings in
+    /// `eval_at_point`.
+    OutermostFirst,
+    /// `folding_factors[0]` is applied at the bottom layer, combining adjacent values. This is
+    /// the order FRI draws its folding alphas in, since each FRI round folds adjacent pairs of a
+    /// bit-reversed evaluation.
+    InnermostFirst,
+}
+
+/// Same as [`fold`] but with an explicit order of the folding factors.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+pub fn fold_with_order<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[E],
+    order: FoldOrder,
+) -> E {
+    match order {
+        FoldOrder::OutermostFirst => fold_checked(values, folding_factors).unwrap(),
+        FoldOrder::InnermostFirst => {
+            let folding_factors: Vec<E> = folding_factors.iter().rev().copied().collect();
+            fold_checked(values, &folding_factors).unwrap()
+        }
+    }
+}
+
+/// Same as [`fold`] but returns an error instead of panicking on invalid input lengths.
+pub fn fold_checked<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[E],
+) -> Result<E, FoldError> {
+    FoldInput::new(values, folding_factors).map(|input| input.fold())
+}
+
+/// Values and folding factors whose lengths have been validated for [`fold`].
+#[derive(Clone, Copy, Debug)]
+pub struct FoldInput<'a, F: Field, E: ExtensionOf<F>> {
+    values: &'a [F],
+    folding_factors: &'a [E],
+}
+
+impl<'a, F: Field, E: ExtensionOf<F>> FoldInput<'a, F, E> {
+    /// Returns an error if the number of values is not a power of two or if an incorrect number
+    /// of folding factors is provided.
+    pub fn new(values: &'a [F], folding_factors: &'a [E]) -> Result<Self, FoldError> {
+        validate_fold_lengths(values.len(), folding_factors.len())?;
+        Ok(Self {
+            values,
+            folding_factors,
+        })
+    }
+
+    pub fn values(&self) -> &'a [F] {
+        self.values
+    }
+
+    pub fn folding_factors(&self) -> &'a [E] {
+        self.folding_factors
+    }
+
+    /// Folds the values as in [`fold`].
+    pub fn fold(&self) -> E {
+        #[cfg(not(feature = "parallel"))]
+        let res = fold_layers(self.values, self.folding_factors, &mut Vec::new());
+
+        #[cfg(feature = "parallel")]
+        let res = fold_layers_parallel(self.values, self.folding_factors);
+
+        res
+    }
+}
+
+/// Inputs with fewer than `2^PARALLEL_FOLD_MIN_LOG_SIZE` values are folded on a single thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_FOLD_MIN_LOG_SIZE: u32 = 16;
+
+/// Same as [`fold`] but folds the top layers of large inputs on the rayon thread pool.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+#[cfg(feature = "parallel")]
+pub fn fold_parallel<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+    FoldInput::new(values, folding_factors).unwrap().fold()
+}
+
+/// Splits the top layers across threads until the halves are small enough to fold serially.
+#[cfg(feature = "parallel")]
+fn fold_layers_parallel<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
     let n = values.len();
-    assert_eq!(n, 1 << folding_factors.len());
-    if n == 1 {
-        return values[0].into();
+    debug_assert_eq!(n, 1 << folding_factors.len());
+    if n < 1 << PARALLEL_FOLD_MIN_LOG_SIZE {
+        return fold_layers(values, folding_factors, &mut Vec::new());
     }
     let (lhs_values, rhs_values) = values.split_at(n / 2);
-    let (folding_factor, folding_factors) = folding_factors.split_first().unwrap();
-    let lhs_val = fold(lhs_values, folding_factors);
-    let rhs_val = fold(rhs_values, folding_factors);
-    lhs_val + rhs_val * *folding_factor
+    let (&folding_factor, folding_factors) = folding_factors.split_first().unwrap();
+    let (lhs_val, rhs_val) = rayon::join(
+        || fold_layers_parallel(lhs_values, folding_factors),
+        || fold_layers_parallel(rhs_values, folding_factors),
+    );
+    lhs_val + rhs_val * folding_factor
+}
+
+/// Same as [`fold`] but uses `scratch` to store the intermediate layers.
+///
+/// Passing the same buffer across calls avoids reallocating in hot loops. The contents of
+/// `scratch` on entry are ignored.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+pub fn fold_with_scratch<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[E],
+    scratch: &mut Vec<E>,
+) -> E {
+    let input = FoldInput::new(values, folding_factors).unwrap();
+    fold_layers(input.values(), input.folding_factors(), scratch)
+}
+
+/// Folds `values` as in [`fold`] and adds the result times `weight` to `acc`.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+pub fn fold_into<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[E],
+    acc: &mut E,
+    weight: E,
+) {
+    *acc += weight * fold(values, folding_factors);
+}
+
+/// Folds each of `columns` with the same `folding_factors`, as in [`fold`].
+///
+/// Returns one folded value per column, in the order of `columns`.
+///
+/// # Panics
+///
+/// Panics if the columns don't all have the same length, or under the same conditions as [`fold`]
+/// for that length.
+pub fn fold_many<F: Field, E: ExtensionOf<F>>(columns: &[&[F]], folding_factors: &[E]) -> Vec<E> {
+    let Some(first_column) = columns.first() else {
+        return Vec::new();
+    };
+    let n = first_column.len();
+    assert!(columns.iter().all(|column| column.len() == n));
+    let mut scratch = Vec::new();
+    columns
+        .iter()
+        .map(|column| {
+            let input = FoldInput::new(column, folding_factors).unwrap();
+            fold_layers(input.values(), input.folding_factors(), &mut scratch)
+        })
+        .collect()
+}
+
+/// Same as [`fold`] for a number of layers known at compile time.
+///
+/// The lengths are enforced by the types, so there are no runtime checks and the loops can be
+/// fully unrolled. Sizing `values` by `1 << LAYERS` relies on the crate's `generic_const_exprs`
+/// nightly feature.
+pub fn fold_const<const LAYERS: usize, F: Field, E: ExtensionOf<F>>(
+    values: &[F; 1 << LAYERS],
+    folding_factors: &[E; LAYERS],
+) -> E
+where
+    [(); 1 << LAYERS]:,
+{
+    let mut layer = values.map(E::from);
+    for (i, &folding_factor) in folding_factors.iter().rev().enumerate() {
+        for j in 0..1 << (LAYERS - 1 - i) {
+            layer[j] = layer[2 * j] + layer[2 * j + 1] * folding_factor;
+        }
+    }
+    layer[0]
+}
+
+/// Same as [`fold`] but first pads `values` with `pad` up to the next power of two.
+///
+/// `folding_factors` must have one factor per layer of the padded values, i.e.
+/// `values.len().next_power_of_two().ilog2()` factors.
+///
+/// # Panics
+///
+/// Panics if `values` is empty or if an incorrect number of folding factors is provided.
+pub fn fold_padded<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E], pad: F) -> E {
+    assert!(!values.is_empty(), "cannot fold zero values");
+    let n = values.len();
+    if n.is_power_of_two() {
+        return fold(values, folding_factors);
+    }
+    let mut padded_values = Vec::with_capacity(n.next_power_of_two());
+    padded_values.extend_from_slice(values);
+    padded_values.resize(n.next_power_of_two(), pad);
+    fold(&padded_values, folding_factors)
+}
+
+/// Same as [`fold_padded`] with zero padding.
+///
+/// Zero values add nothing to any layer, so this is `sum_i values[i] * c_i` where `c_i` is the
+/// product of the factors selected by the bits of `i`, exactly as if the padded values weren't
+/// there.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold_padded`].
+pub fn fold_zero_padded<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+    fold_padded(values, folding_factors, F::zero())
+}
+
+/// Same as [`fold`] but returns every layer of the folding tree, for debugging.
+///
+/// Layer `0` is `values` cast into `E`, each following layer is half the length of the previous
+/// one, and the last layer holds the single value [`fold`] returns.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fold`].
+pub fn fold_trace<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> Vec<Vec<E>> {
+    let input = FoldInput::new(values, folding_factors).unwrap();
+    let mut layers = vec![input.values().iter().map(|&v| v.into()).collect_vec()];
+    for &folding_factor in input.folding_factors().iter().rev() {
+        let layer = layers.last().unwrap();
+        let folded_layer = layer
+            .array_chunks()
+            .map(|&[lhs, rhs]| lhs + rhs * folding_factor)
+            .collect();
+        layers.push(folded_layer);
+    }
+    layers
+}
+
+/// Folds values with the powers of a single `alpha` as folding factors.
+///
+/// For `n = 2^k` values the factors are `[alpha^(2^(k-1)), ..., alpha^2, alpha]`, outermost layer
+/// first, so the result is `sum_i values[i] * alpha^i`. The factors are generated by repeated
+/// squaring while folding from the innermost layer out.
+///
+/// # Panics
+///
+/// Panics if the number of values is not a power of two.
+pub fn fold_powers<F: Field, E: ExtensionOf<F>>(values: &[F], alpha: E) -> E {
+    let mut folder = Folder::new(values);
+    let mut folding_factor = alpha;
+    for _ in 0..folder.n_remaining_layers() {
+        folder.fold_layer(folding_factor);
+        folding_factor = folding_factor.square();
+    }
+    folder.finish()
+}
+
+/// Folds values one layer at a time, for when the folding factors aren't all known up front.
+///
+/// Each call to [`Folder::fold_layer`] folds the innermost remaining layer, so feeding the
+/// factors of [`fold`] in reverse order reproduces its result.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stwo_prover::core::fields::m31::BaseField;
+/// # use stwo_prover::core::poly::utils::{fold, Folder};
+/// let values = [1, 2, 3, 4].map(BaseField::from);
+/// let folding_factors = [5, 6].map(BaseField::from);
+/// let mut folder = Folder::new(&values);
+/// folder.fold_layer(folding_factors[1]);
+/// folder.fold_layer(folding_factors[0]);
+/// assert_eq!(folder.finish(), fold(&values, &folding_factors));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Folder<'a, F: Field, E: ExtensionOf<F>> {
+    values: &'a [F],
+    /// The current layer. Empty until the first layer has been folded.
+    layer: Vec<E>,
+}
+
+impl<'a, F: Field, E: ExtensionOf<F>> Folder<'a, F, E> {
+    /// # Panics
+    ///
+    /// Panics if the number of values is not a power of two.
+    pub fn new(values: &'a [F]) -> Self {
+        assert!(values.len().is_power_of_two());
+        Self {
+            values,
+            layer: Vec::new(),
+        }
+    }
+
+    /// Returns the number of layers left to fold.
+    pub fn n_remaining_layers(&self) -> u32 {
+        if self.layer.is_empty() {
+            self.values.len().ilog2()
+        } else {
+            self.layer.len().ilog2()
+        }
+    }
+
+    /// Folds the innermost remaining layer with `folding_factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all layers have already been folded.
+    pub fn fold_layer(&mut self, folding_factor: E) {
+        assert!(
+            self.n_remaining_layers() > 0,
+            "all layers are already folded"
+        );
+        if self.layer.is_empty() {
+            self.layer = self
+                .values
+                .array_chunks()
+                .map(|&[lhs, rhs]| folding_factor * rhs + lhs)
+                .collect();
+            return;
+        }
+        let n_folded = self.layer.len() / 2;
+        for i in 0..n_folded {
+            self.layer[i] = self.layer[2 * i] + self.layer[2 * i + 1] * folding_factor;
+        }
+        self.layer.truncate(n_folded);
+    }
+
+    /// Returns the folded value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not all layers have been folded.
+    pub fn finish(self) -> E {
+        assert_eq!(self.n_remaining_layers(), 0, "not all layers are folded");
+        match self.layer.first() {
+            Some(&value) => value,
+            None => self.values[0].into(),
+        }
+    }
+}
+
+/// Generalizes [`fold`] to fold `radix` adjacent values per layer instead of two.
+///
+/// Each entry of `folding_factors` holds the `radix - 1` factors of one layer, outermost layer
+/// first. A layer with factors `[f_1, ..., f_{radix-1}]` combines values `v_0, ..., v_{radix-1}`
+/// into `v_0 + f_1 * v_1 + ... + f_{radix-1} * v_{radix-1}`. With `radix == 2` this is exactly
+/// [`fold`].
+///
+/// # Panics
+///
+/// Panics if `radix < 2`, if the number of values is not `radix^folding_factors.len()` or if a
+/// layer doesn't have exactly `radix - 1` factors.
+pub fn fold_radix<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[Vec<E>],
+    radix: usize,
+) -> E {
+    assert!(radix >= 2, "radix must be at least 2");
+    let n_layers = folding_factors.len() as u32;
+    assert_eq!(
+        Some(values.len()),
+        radix.checked_pow(n_layers),
+        "number of values is not {radix}^{n_layers}"
+    );
+    assert!(
+        folding_factors
+            .iter()
+            .all(|layer_factors| layer_factors.len() == radix - 1),
+        "each layer needs {} folding factors",
+        radix - 1
+    );
+
+    let Some((innermost_factors, folding_factors)) = folding_factors.split_last() else {
+        return values[0].into();
+    };
+    let mut layer: Vec<E> = values
+        .chunks_exact(radix)
+        .map(|chunk| fold_radix_chunk(chunk, innermost_factors))
+        .collect();
+    for layer_factors in folding_factors.iter().rev() {
+        layer = layer
+            .chunks_exact(radix)
+            .map(|chunk| fold_radix_chunk(chunk, layer_factors))
+            .collect();
+    }
+    layer[0]
+}
+
+fn fold_radix_chunk<V: Field, E: ExtensionOf<V>>(chunk: &[V], folding_factors: &[E]) -> E {
+    let (&first, rest) = chunk.split_first().unwrap();
+    rest.iter()
+        .zip(folding_factors)
+        .fold(first.into(), |acc, (&v, &factor)| acc + factor * v)
+}
+
+/// Invalid input lengths for [`fold`], as reported by [`fold_checked`] and [`FoldInput::new`].
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+pub enum FoldError {
+    #[error("number of values {len} is not a power of two")]
+    NotPowerOfTwo { len: usize },
+    #[error("expected {expected} folding factors but got {got}")]
+    FactorCountMismatch { expected: usize, got: usize },
+}
+
+fn validate_fold_lengths(n_values: usize, n_folding_factors: usize) -> Result<(), FoldError> {
+    if !n_values.is_power_of_two() {
+        return Err(FoldError::NotPowerOfTwo { len: n_values });
+    }
+    let expected = n_values.ilog2() as usize;
+    if n_folding_factors != expected {
+        return Err(FoldError::FactorCountMismatch {
+            expected,
+            got: n_folding_factors,
+        });
+    }
+    Ok(())
+}
+
+/// Folds `values` assuming lengths have already been validated by the public entry point.
+///
+/// Lengths are only re-checked in debug builds.
+fn fold_layers<F: Field, E: ExtensionOf<F>>(
+    values: &[F],
+    folding_factors: &[E],
+    scratch: &mut Vec<E>,
+) -> E {
+    let n = values.len();
+    debug_assert_eq!(n, 1 << folding_factors.len());
+    if n <= 8 {
+        return fold_small(values, folding_factors);
+    }
+
+    // Fold the three bottom layers straight from `values` so they never need to be copied into
+    // `E`.
+    let (folding_factors, innermost_factors) = folding_factors.split_at(folding_factors.len() - 3);
+    let innermost_factors = innermost_factors.try_into().unwrap();
+    scratch.clear();
+    scratch.extend(
+        values
+            .array_chunks()
+            .map(|chunk| fold8(chunk, innermost_factors)),
+    );
+
+    // Fold the remaining layers in place. Entry `i` is only written after entries `2i` and
+    // `2i + 1` have been read.
+    for &folding_factor in folding_factors.iter().rev() {
+        let n_folded = scratch.len() / 2;
+        for i in 0..n_folded {
+            scratch[i] = scratch[2 * i] + scratch[2 * i + 1] * folding_factor;
+        }
+        scratch.truncate(n_folded);
+    }
+
+    scratch[0]
+}
+
+/// Folds at most 8 values using the unrolled base cases.
+fn fold_small<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+    match folding_factors.len() {
+        0 => values[0].into(),
+        1 => fold2(
+            values.try_into().unwrap(),
+            folding_factors.try_into().unwrap(),
+        ),
+        2 => fold4(
+            values.try_into().unwrap(),
+            folding_factors.try_into().unwrap(),
+        ),
+        3 => fold8(
+            values.try_into().unwrap(),
+            folding_factors.try_into().unwrap(),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[inline(always)]
+fn fold2<F: Field, E: ExtensionOf<F>>(&[a, b]: &[F; 2], &[x]: &[E; 1]) -> E {
+    x * b + a
+}
+
+#[inline(always)]
+fn fold4<F: Field, E: ExtensionOf<F>>(&[a, b, c, d]: &[F; 4], &[x, y]: &[E; 2]) -> E {
+    let n1 = y * b + a;
+    let n2 = y * d + c;
+    n1 + n2 * x
+}
+
+#[inline(always)]
+fn fold8<F: Field, E: ExtensionOf<F>>(
+    &[a, b, c, d, e, f, g, h]: &[F; 8],
+    &[x, y, z]: &[E; 3],
+) -> E {
+    let n3 = z * b + a;
+    let n4 = z * d + c;
+    let n5 = z * f + e;
+    let n6 = z * h + g;
+    let n1 = n3 + n4 * y;
+    let n2 = n5 + n6 * y;
+    n1 + n2 * x
 }
 
 /// Repeats each value sequentially `duplicity` many times.
@@ -76,10 +573,467 @@ pub fn domain_line_twiddles_from_tree<T>(
 
 #[cfg(test)]
 mod tests {
-    use super::repeat_value;
+    use std::iter::zip;
+
+    use itertools::Itertools;
+    use num_traits::Zero;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::{
+        fold, fold_checked, fold_const, fold_into, fold_many, fold_padded, fold_powers, fold_radix,
+        fold_trace, fold_with_order, fold_with_scratch, fold_zero_padded, repeat_value, FoldError,
+        FoldInput, FoldOrder, Folder,
+    };
+    use crate::core::backend::CpuBackend;
+    use crate::core::fields::m31::BaseField;
+    use crate::core::fields::qm31::SecureField;
+    use crate::core::fields::{ExtensionOf, Field, FieldExpOps};
     use crate::core::poly::circle::CanonicCoset;
     use crate::core::poly::line::LineDomain;
     use crate::core::poly::utils::domain_line_twiddles_from_tree;
+    use crate::core::test_utils::{gen_random_base_column, gen_random_folding_factors};
+
+    /// The original recursive `fold`, kept as a reference.
+    fn fold_recursive<F: Field, E: ExtensionOf<F>>(values: &[F], folding_factors: &[E]) -> E {
+        let n = values.len();
+        assert_eq!(n, 1 << folding_factors.len());
+        if n == 1 {
+            return values[0].into();
+        }
+        let (lhs_values, rhs_values) = values.split_at(n / 2);
+        let (folding_factor, folding_factors) = folding_factors.split_first().unwrap();
+        let lhs_val = fold_recursive(lhs_values, folding_factors);
+        let rhs_val = fold_recursive(rhs_values, folding_factors);
+        lhs_val + rhs_val * *folding_factor
+    }
+
+    #[test]
+    fn fold_works() {
+        let values = [1, 2, 3, 4, 5, 6, 7, 8].map(BaseField::from);
+        let folding_factors = [10000, 100, 10].map(BaseField::from);
+
+        let res = fold(&values, &folding_factors);
+
+        // The factors are chosen so that `values[i]` is scaled by `10^i`.
+        assert_eq!(res, BaseField::from(87654321));
+    }
+
+    #[test]
+    fn fold_matches_recursive_fold() {
+        for log_n in 0..12 {
+            let values = gen_random_base_column::<CpuBackend>(log_n, 0);
+            let folding_factors = gen_random_folding_factors(log_n as usize, 1);
+
+            let res = fold(&values, &folding_factors);
+
+            assert_eq!(res, fold_recursive(&values, &folding_factors));
+        }
+    }
+
+    #[test]
+    fn fold_secure_values_works() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for log_n in [0, 2, 3, 9] {
+            let values: Vec<SecureField> = (0..1 << log_n).map(|_| rng.gen()).collect();
+            let folding_factors: Vec<SecureField> = (0..log_n).map(|_| rng.gen()).collect();
+
+            let res = fold(&values, &folding_factors);
+
+            assert_eq!(res, fold_recursive(&values, &folding_factors));
+        }
+    }
+
+    #[test]
+    fn fold_with_scratch_reuses_buffer() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut scratch = Vec::new();
+        for log_n in [6, 3, 8] {
+            let values: Vec<BaseField> = (0..1 << log_n).map(|_| rng.gen()).collect();
+            let folding_factors: Vec<SecureField> = (0..log_n).map(|_| rng.gen()).collect();
+
+            let res = fold_with_scratch(&values, &folding_factors, &mut scratch);
+
+            assert_eq!(res, fold(&values, &folding_factors));
+        }
+    }
+
+    #[test]
+    fn fold_checked_with_non_power_of_two_len_fails() {
+        let values = [BaseField::from(1); 6];
+        let folding_factors = [BaseField::from(2); 2];
+
+        let res = fold_checked(&values, &folding_factors);
+
+        assert_eq!(res, Err(FoldError::NotPowerOfTwo { len: 6 }));
+    }
+
+    #[test]
+    fn fold_checked_with_wrong_number_of_factors_fails() {
+        let values = [BaseField::from(1); 8];
+        let folding_factors = [BaseField::from(2); 2];
+
+        let res = fold_checked(&values, &folding_factors);
+
+        assert_eq!(
+            res,
+            Err(FoldError::FactorCountMismatch {
+                expected: 3,
+                got: 2
+            })
+        );
+    }
+
+    #[test]
+    fn fold_checked_with_valid_input_works() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 5).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..5).map(|_| rng.gen()).collect();
+
+        let res = fold_checked(&values, &folding_factors);
+
+        assert_eq!(res, Ok(fold(&values, &folding_factors)));
+    }
+
+    #[test]
+    fn fold_into_matches_weighted_sum_of_folds() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let columns: Vec<Vec<BaseField>> = (0..3)
+            .map(|_| (0..1 << 5).map(|_| rng.gen()).collect())
+            .collect();
+        let folding_factors: Vec<SecureField> = (0..5).map(|_| rng.gen()).collect();
+        let weights: [SecureField; 3] = rng.gen();
+        let mut acc = SecureField::zero();
+
+        for (column, &weight) in zip(&columns, &weights) {
+            fold_into(column, &folding_factors, &mut acc, weight);
+        }
+
+        let expected = zip(&columns, weights)
+            .map(|(column, weight)| weight * fold(column, &folding_factors))
+            .sum::<SecureField>();
+        assert_eq!(acc, expected);
+    }
+
+    #[test]
+    fn fold_many_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let columns: Vec<Vec<BaseField>> = (0..5)
+            .map(|_| (0..1 << 7).map(|_| rng.gen()).collect())
+            .collect();
+        let column_refs: Vec<&[BaseField]> = columns.iter().map(|c| c.as_slice()).collect();
+        let folding_factors: Vec<SecureField> = (0..7).map(|_| rng.gen()).collect();
+
+        let res = fold_many(&column_refs, &folding_factors);
+
+        let expected: Vec<SecureField> = columns
+            .iter()
+            .map(|column| fold(column, &folding_factors))
+            .collect();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fold_many_with_different_column_lengths_fails() {
+        let lhs = [BaseField::from(1); 8];
+        let rhs = [BaseField::from(1); 4];
+        let folding_factors = [BaseField::from(2); 3];
+
+        fold_many(&[&lhs, &rhs], &folding_factors);
+    }
+
+    #[test]
+    fn fold_radix_2_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 6).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..6).map(|_| rng.gen()).collect();
+        let radix_folding_factors = folding_factors.iter().map(|&f| vec![f]).collect_vec();
+
+        let res = fold_radix(&values, &radix_folding_factors, 2);
+
+        assert_eq!(res, fold(&values, &folding_factors));
+    }
+
+    #[test]
+    fn fold_radix_4_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 6).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..6).map(|_| rng.gen()).collect();
+        // Two binary layers with factors `[outer, inner]` scale four adjacent values by
+        // `[1, inner, outer, outer * inner]`.
+        let radix_folding_factors = folding_factors
+            .chunks(2)
+            .map(|f| vec![f[1], f[0], f[0] * f[1]])
+            .collect_vec();
+
+        let res = fold_radix(&values, &radix_folding_factors, 4);
+
+        assert_eq!(res, fold(&values, &folding_factors));
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values is not 4^2")]
+    fn fold_radix_with_non_power_of_radix_len_fails() {
+        let values = [BaseField::from(1); 8];
+        let folding_factors = vec![vec![BaseField::from(2); 3]; 2];
+
+        fold_radix(&values, &folding_factors, 4);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn fold_parallel_matches_serial_fold() {
+        use super::{fold_layers, fold_parallel, PARALLEL_FOLD_MIN_LOG_SIZE};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for log_n in [
+            4,
+            PARALLEL_FOLD_MIN_LOG_SIZE,
+            PARALLEL_FOLD_MIN_LOG_SIZE + 3,
+        ] {
+            let values: Vec<BaseField> = (0..1 << log_n).map(|_| rng.gen()).collect();
+            let folding_factors: Vec<SecureField> = (0..log_n).map(|_| rng.gen()).collect();
+
+            let res = fold_parallel(&values, &folding_factors);
+
+            let expected = fold_layers(&values, &folding_factors, &mut Vec::new());
+            assert_eq!(res, expected);
+        }
+    }
+
+    #[test]
+    fn fold_with_order_reversed_factors_match() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 6).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..6).map(|_| rng.gen()).collect();
+        let reversed_folding_factors = folding_factors.iter().rev().copied().collect_vec();
+
+        let outermost_first = fold_with_order(&values, &folding_factors, FoldOrder::OutermostFirst);
+        let innermost_first = fold_with_order(
+            &values,
+            &reversed_folding_factors,
+            FoldOrder::InnermostFirst,
+        );
+
+        assert_eq!(outermost_first, innermost_first);
+        assert_eq!(outermost_first, fold(&values, &folding_factors));
+    }
+
+    #[test]
+    fn fold_with_order_innermost_first_differs_from_outermost_first() {
+        let values = [1, 2, 3, 4].map(BaseField::from);
+        let folding_factors = [10, 100].map(BaseField::from);
+
+        let outermost_first = fold_with_order(&values, &folding_factors, FoldOrder::OutermostFirst);
+        let innermost_first = fold_with_order(&values, &folding_factors, FoldOrder::InnermostFirst);
+
+        assert_eq!(outermost_first, BaseField::from(1 + 200 + 30 + 4000));
+        assert_eq!(innermost_first, BaseField::from(1 + 20 + 300 + 4000));
+    }
+
+    #[test]
+    fn fold_const_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: [BaseField; 8] = rng.gen();
+        let folding_factors: [SecureField; 3] = rng.gen();
+
+        assert_eq!(
+            fold_const::<1, _, _>(values.first_chunk().unwrap(), &[folding_factors[0]]),
+            fold(&values[..2], &folding_factors[..1])
+        );
+        assert_eq!(
+            fold_const::<2, _, _>(
+                values.first_chunk().unwrap(),
+                &[folding_factors[0], folding_factors[1]]
+            ),
+            fold(&values[..4], &folding_factors[..2])
+        );
+        assert_eq!(
+            fold_const(&values, &folding_factors),
+            fold(&values, &folding_factors)
+        );
+    }
+
+    #[test]
+    fn fold_padded_6_values_works() {
+        let values = [1, 2, 3, 4, 5, 6].map(BaseField::from);
+        let folding_factors = [10000, 100, 10].map(BaseField::from);
+        let pad = BaseField::from(9);
+
+        let res = fold_padded(&values, &folding_factors, pad);
+
+        assert_eq!(res, BaseField::from(99654321));
+    }
+
+    #[test]
+    fn fold_padded_100_values_works() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..100).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..7).map(|_| rng.gen()).collect();
+        let pad = rng.gen();
+
+        let res = fold_padded(&values, &folding_factors, pad);
+
+        let mut padded_values = values.clone();
+        padded_values.resize(128, pad);
+        assert_eq!(res, fold(&padded_values, &folding_factors));
+    }
+
+    #[test]
+    fn fold_zero_padded_ignores_padding() {
+        let values = [1, 2, 3, 4, 5, 6].map(BaseField::from);
+        let folding_factors = [10000, 100, 10].map(BaseField::from);
+
+        let res = fold_zero_padded(&values, &folding_factors);
+
+        assert_eq!(res, BaseField::from(654321));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fold_padded_with_wrong_number_of_factors_fails() {
+        let values = [BaseField::from(1); 6];
+        let folding_factors = [BaseField::from(2); 2];
+
+        fold_zero_padded(&values, &folding_factors);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot fold zero values")]
+    fn fold_padded_with_no_values_fails() {
+        let values: [BaseField; 0] = [];
+        let folding_factors: [BaseField; 0] = [];
+
+        fold_padded(&values, &folding_factors, BaseField::from(1));
+    }
+
+    #[test]
+    fn fold_trace_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 6).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..6).map(|_| rng.gen()).collect();
+
+        let layers = fold_trace(&values, &folding_factors);
+
+        assert_eq!(layers.len(), 7);
+        assert_eq!(
+            layers[0],
+            values.iter().map(|&v| SecureField::from(v)).collect_vec()
+        );
+        for (layer, next_layer) in layers.iter().tuple_windows() {
+            assert_eq!(next_layer.len(), layer.len() / 2);
+        }
+        assert_eq!(*layers.last().unwrap(), [fold(&values, &folding_factors)]);
+    }
+
+    #[test]
+    fn fold_powers_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 6).map(|_| rng.gen()).collect();
+        let alpha: SecureField = rng.gen();
+        let folding_factors = (0..6).rev().map(|i| alpha.pow(1 << i)).collect_vec();
+
+        let res = fold_powers(&values, alpha);
+
+        assert_eq!(res, fold(&values, &folding_factors));
+    }
+
+    #[test]
+    fn fold_powers_evaluates_polynomial() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 5).map(|_| rng.gen()).collect();
+        let alpha: SecureField = rng.gen();
+
+        let res = fold_powers(&values, alpha);
+
+        let expected = values
+            .iter()
+            .rfold(SecureField::zero(), |acc, &v| acc * alpha + v);
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn folder_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for log_n in 0..8 {
+            let values: Vec<BaseField> = (0..1 << log_n).map(|_| rng.gen()).collect();
+            let folding_factors: Vec<SecureField> = (0..log_n).map(|_| rng.gen()).collect();
+            let mut folder = Folder::new(&values);
+
+            for &folding_factor in folding_factors.iter().rev() {
+                folder.fold_layer(folding_factor);
+            }
+
+            assert_eq!(folder.finish(), fold(&values, &folding_factors));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not all layers are folded")]
+    fn folder_finish_with_remaining_layers_fails() {
+        let values = [BaseField::from(1); 4];
+        let mut folder = Folder::new(&values);
+        folder.fold_layer(SecureField::from(2));
+
+        folder.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "all layers are already folded")]
+    fn folder_fold_too_many_layers_fails() {
+        let values = [BaseField::from(1); 2];
+        let mut folder = Folder::new(&values);
+        folder.fold_layer(SecureField::from(2));
+
+        folder.fold_layer(SecureField::from(2));
+    }
+
+    #[test]
+    fn fold_input_with_mismatched_lengths_fails() {
+        let values = [BaseField::from(1); 8];
+        let folding_factors = [SecureField::from(2); 2];
+
+        let res = FoldInput::new(&values, &folding_factors);
+
+        assert_eq!(
+            res.unwrap_err(),
+            FoldError::FactorCountMismatch {
+                expected: 3,
+                got: 2
+            }
+        );
+    }
+
+    #[test]
+    fn fold_input_with_non_power_of_two_len_fails() {
+        let values = [BaseField::from(1); 0];
+        let folding_factors = [SecureField::from(2); 0];
+
+        let res = FoldInput::new(&values, &folding_factors);
+
+        assert_eq!(res.unwrap_err(), FoldError::NotPowerOfTwo { len: 0 });
+    }
+
+    #[test]
+    fn fold_input_fold_matches_fold() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..1 << 5).map(|_| rng.gen()).collect();
+        let folding_factors: Vec<SecureField> = (0..5).map(|_| rng.gen()).collect();
+
+        let input = FoldInput::new(&values, &folding_factors).unwrap();
+
+        assert_eq!(input.fold(), fold(&values, &folding_factors));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fold_with_wrong_number_of_factors_fails() {
+        let values = [BaseField::from(1); 8];
+        let folding_factors = [BaseField::from(2); 2];
+
+        fold(&values, &folding_factors);
+    }
 
     #[test]
     fn repeat_value_0_times_works() {
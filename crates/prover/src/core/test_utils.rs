@@ -1,7 +1,17 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
 use super::backend::cpu::CpuCircleEvaluation;
+use super::backend::{Col, ColumnOps};
 use super::channel::Blake2sChannel;
 use super::fields::m31::BaseField;
 use super::fields::qm31::SecureField;
+use super::fields::{ExtensionOf, Field};
+use super::poly::utils::fold;
 
 pub fn secure_eval_to_base_eval<EvalOrder>(
     eval: &CpuCircleEvaluation<SecureField, EvalOrder>,
@@ -15,3 +25,152 @@ pub fn secure_eval_to_base_eval<EvalOrder>(
 pub fn test_channel() -> Blake2sChannel {
     Blake2sChannel::default()
 }
+
+/// Returns a column of `2^log_size` random values, fully determined by `seed`.
+pub fn gen_random_base_column<B: ColumnOps<BaseField>>(
+    log_size: u32,
+    seed: u64,
+) -> Col<B, BaseField> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..1 << log_size).map(|_| rng.gen()).collect()
+}
+
+/// Returns a column of `2^log_size` random values, fully determined by `seed`.
+pub fn gen_random_secure_column<B: ColumnOps<SecureField>>(
+    log_size: u32,
+    seed: u64,
+) -> Col<B, SecureField> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..1 << log_size).map(|_| rng.gen()).collect()
+}
+
+/// Returns `n` random folding factors, fully determined by `seed`.
+///
+/// Use a different seed than for the folded values, so the factors aren't correlated with them.
+pub fn gen_random_folding_factors(n: usize, seed: u64) -> Vec<SecureField> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..n).map(|_| rng.gen()).collect()
+}
+
+/// The exact inputs of a [`fold`] call.
+type FoldKey<F, E> = (Vec<F>, Vec<E>);
+
+/// Memoizes [`fold`] by its exact inputs, for tests that repeatedly fold the same column.
+///
+/// Safe to share between threads.
+#[derive(Debug, Default)]
+pub struct CachedFold<F, E> {
+    cache: Mutex<HashMap<FoldKey<F, E>, E>>,
+}
+
+impl<F: Field + Hash, E: ExtensionOf<F> + Hash> CachedFold<F, E> {
+    pub fn new() -> Self {
+        Self {
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached result of [`fold`] for these inputs, computing it on a miss.
+    pub fn fold(&self, values: &[F], folding_factors: &[E]) -> E {
+        let key = (values.to_vec(), folding_factors.to_vec());
+        if let Some(&res) = self.cache.lock().unwrap().get(&key) {
+            return res;
+        }
+        // Fold without holding the lock so other threads aren't blocked.
+        let res = fold(values, folding_factors);
+        self.cache.lock().unwrap().insert(key, res);
+        res
+    }
+
+    /// Returns the number of distinct inputs folded so far.
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::{
+        gen_random_base_column, gen_random_folding_factors, gen_random_secure_column, CachedFold,
+    };
+    use crate::core::backend::simd::SimdBackend;
+    use crate::core::backend::{Column, CpuBackend};
+    use crate::core::fields::m31::BaseField;
+    use crate::core::fields::qm31::SecureField;
+    use crate::core::poly::utils::fold;
+
+    #[test]
+    fn gen_random_columns_are_deterministic() {
+        let base_column = gen_random_base_column::<CpuBackend>(8, 42);
+        let secure_column = gen_random_secure_column::<CpuBackend>(8, 42);
+
+        assert_eq!(base_column, gen_random_base_column::<CpuBackend>(8, 42));
+        assert_eq!(secure_column, gen_random_secure_column::<CpuBackend>(8, 42));
+        assert_ne!(base_column, gen_random_base_column::<CpuBackend>(8, 43));
+        assert_ne!(secure_column, gen_random_secure_column::<CpuBackend>(8, 43));
+    }
+
+    #[test]
+    fn gen_random_columns_match_across_backends() {
+        let cpu_base_column = gen_random_base_column::<CpuBackend>(8, 42);
+        let cpu_secure_column = gen_random_secure_column::<CpuBackend>(8, 42);
+
+        let simd_base_column = gen_random_base_column::<SimdBackend>(8, 42);
+        let simd_secure_column = gen_random_secure_column::<SimdBackend>(8, 42);
+
+        assert_eq!(simd_base_column.to_cpu(), cpu_base_column);
+        assert_eq!(simd_secure_column.to_cpu(), cpu_secure_column);
+    }
+
+    #[test]
+    fn cached_fold_hit_returns_cached_value() {
+        let values = gen_random_base_column::<CpuBackend>(6, 0);
+        let folding_factors = gen_random_folding_factors(6, 1);
+        let cached_fold = CachedFold::new();
+        let res = cached_fold.fold(&values, &folding_factors);
+
+        let cached_res = cached_fold.fold(&values, &folding_factors);
+
+        assert_eq!(cached_res, res);
+        assert_eq!(cached_res, fold(&values, &folding_factors));
+        assert_eq!(cached_fold.len(), 1);
+    }
+
+    #[test]
+    fn cached_fold_miss_recomputes() {
+        let mut values = gen_random_base_column::<CpuBackend>(6, 0);
+        let folding_factors = gen_random_folding_factors(6, 1);
+        let cached_fold = CachedFold::new();
+        cached_fold.fold(&values, &folding_factors);
+        values[5] += BaseField::from(1);
+
+        let res = cached_fold.fold(&values, &folding_factors);
+
+        assert_eq!(res, fold(&values, &folding_factors));
+        assert_eq!(cached_fold.len(), 2);
+    }
+
+    #[test]
+    fn cached_fold_is_shareable_between_threads() {
+        let values = gen_random_base_column::<CpuBackend>(6, 0);
+        let folding_factors = gen_random_folding_factors(6, 1);
+        let cached_fold = CachedFold::<BaseField, SecureField>::new();
+
+        let results: Vec<SecureField> = thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| s.spawn(|| cached_fold.fold(&values, &folding_factors)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let expected = fold(&values, &folding_factors);
+        assert!(results.iter().all(|&res| res == expected));
+        assert_eq!(cached_fold.len(), 1);
+    }
+}